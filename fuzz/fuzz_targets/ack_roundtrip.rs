@@ -0,0 +1,20 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use tftp::bytes::{FromBytes, IntoBytes};
+use tftp::packet::Ack;
+
+// Any Ack that decodes successfully must re-encode to the same canonical
+// bytes, and re-decoding those bytes must reproduce the same Ack.
+fuzz_target!(|data: &[u8]| {
+    let ack = match Ack::from_bytes(data) {
+        Ok(ack) => ack,
+        Err(_) => return,
+    };
+
+    let canonical = ack.into_bytes();
+    let re_decoded = Ack::from_bytes(&canonical).expect("re-encoded Ack must still decode");
+
+    assert_eq!(ack, re_decoded);
+    assert_eq!(canonical, re_decoded.into_bytes());
+});
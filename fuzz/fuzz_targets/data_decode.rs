@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use tftp::bytes::FromBytes;
+use tftp::packet::Data;
+
+// Data::from_bytes must never panic on adversarial bytes: either a clean
+// `Ok(Data)` or a clean `io::Error`.
+fuzz_target!(|data: &[u8]| {
+    let _ = Data::from_bytes(data);
+});
@@ -0,0 +1,21 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use tftp::bytes::{FromBytes, IntoBytes};
+use tftp::packet::Data;
+
+// Any Data that decodes successfully must re-encode to the same
+// canonical bytes, and re-decoding those bytes must reproduce the same
+// Data.
+fuzz_target!(|data: &[u8]| {
+    let parsed = match Data::from_bytes(data) {
+        Ok(parsed) => parsed,
+        Err(_) => return,
+    };
+
+    let canonical = parsed.clone().into_bytes();
+    let re_decoded = Data::from_bytes(&canonical).expect("re-encoded Data must still decode");
+
+    assert_eq!(parsed, re_decoded);
+    assert_eq!(canonical, re_decoded.into_bytes());
+});
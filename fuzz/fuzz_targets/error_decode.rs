@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use tftp::bytes::FromBytes;
+use tftp::packet::ErrorPacket;
+
+// ErrorPacket::from_bytes must never panic on adversarial bytes: either a
+// clean `Ok(ErrorPacket)` or a clean `io::Error`.
+fuzz_target!(|data: &[u8]| {
+    let _ = ErrorPacket::from_bytes(data);
+});
@@ -0,0 +1,21 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use tftp::bytes::{FromBytes, IntoBytes};
+use tftp::packet::ErrorPacket;
+
+// Any ErrorPacket that decodes successfully must re-encode to the same
+// canonical bytes, and re-decoding those bytes must reproduce the same
+// ErrorPacket.
+fuzz_target!(|data: &[u8]| {
+    let error = match ErrorPacket::from_bytes(data) {
+        Ok(error) => error,
+        Err(_) => return,
+    };
+
+    let canonical = error.clone().into_bytes();
+    let re_decoded = ErrorPacket::from_bytes(&canonical).expect("re-encoded ErrorPacket must still decode");
+
+    assert_eq!(error, re_decoded);
+    assert_eq!(canonical, re_decoded.into_bytes());
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+use std::convert::TryFrom;
+
+use libfuzzer_sys::fuzz_target;
+use tftp::packet::Mode;
+
+// Mode::try_from(String) must reject unrecognized transfer modes with a
+// clean error instead of panicking, regardless of byte content or case.
+fuzz_target!(|data: &[u8]| {
+    let s = String::from_utf8_lossy(data).into_owned();
+    let _ = Mode::try_from(s);
+});
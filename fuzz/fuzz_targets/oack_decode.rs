@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use tftp::bytes::FromBytes;
+use tftp::packet::Oack;
+
+// Oack::from_bytes delegates to options::parse's NUL-scanning loop and
+// must never panic on adversarial bytes.
+fuzz_target!(|data: &[u8]| {
+    let _ = Oack::from_bytes(data);
+});
@@ -0,0 +1,21 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use tftp::bytes::{FromBytes, IntoBytes};
+use tftp::packet::Oack;
+
+// Any Oack that decodes successfully must re-encode to the same
+// canonical bytes, and re-decoding those bytes must reproduce the same
+// Oack.
+fuzz_target!(|data: &[u8]| {
+    let oack = match Oack::from_bytes(data) {
+        Ok(oack) => oack,
+        Err(_) => return,
+    };
+
+    let canonical = oack.clone().into_bytes();
+    let re_decoded = Oack::from_bytes(&canonical).expect("re-encoded Oack must still decode");
+
+    assert_eq!(oack, re_decoded);
+    assert_eq!(canonical, re_decoded.into_bytes());
+});
@@ -0,0 +1,17 @@
+#![no_main]
+
+use std::convert::TryFrom;
+
+use libfuzzer_sys::fuzz_target;
+use tftp::packet::Opcode;
+
+// Opcode::try_from must reject unknown codes with a clean error instead
+// of panicking.
+fuzz_target!(|data: &[u8]| {
+    if data.len() < 2 {
+        return;
+    }
+
+    let val = u16::from_be_bytes([data[0], data[1]]);
+    let _ = Opcode::try_from(val);
+});
@@ -0,0 +1,20 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use tftp::bytes::{FromBytes, IntoBytes};
+use tftp::packet::Rq;
+
+// Any Rq that decodes successfully must re-encode to the same canonical
+// bytes, and re-decoding those bytes must reproduce the same Rq.
+fuzz_target!(|data: &[u8]| {
+    let rq = match Rq::from_bytes(data) {
+        Ok(rq) => rq,
+        Err(_) => return,
+    };
+
+    let canonical = rq.clone().into_bytes();
+    let re_decoded = Rq::from_bytes(&canonical).expect("re-encoded Rq must still decode");
+
+    assert_eq!(rq, re_decoded);
+    assert_eq!(canonical, re_decoded.into_bytes());
+});
@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use tftp::bytes::FromBytes;
+use tftp::packet::Wrq;
+
+// Wrq::from_bytes delegates to the same hand-rolled Rq parsing and must
+// never panic on adversarial bytes.
+fuzz_target!(|data: &[u8]| {
+    let _ = Wrq::from_bytes(data);
+});
@@ -0,0 +1,21 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use tftp::bytes::{FromBytes, IntoBytes};
+use tftp::packet::Wrq;
+
+// Any Wrq that decodes successfully must re-encode to the same canonical
+// bytes, and re-decoding those bytes must reproduce the same Wrq.
+fuzz_target!(|data: &[u8]| {
+    let wrq = match Wrq::from_bytes(data) {
+        Ok(wrq) => wrq,
+        Err(_) => return,
+    };
+
+    let canonical = wrq.clone().into_bytes();
+    let re_decoded =
+        Wrq::from_bytes(&canonical).expect("re-encoded Wrq must still decode");
+
+    assert_eq!(wrq, re_decoded);
+    assert_eq!(canonical, re_decoded.into_bytes());
+});
@@ -0,0 +1,14 @@
+use std::io::Result;
+
+/// Decodes a packet body (the bytes following the 2-byte opcode) from its
+/// wire form.
+pub trait FromBytes: Sized {
+    type Error;
+
+    fn from_bytes<T: AsRef<[u8]>>(bytes: T) -> Result<Self>;
+}
+
+/// Encodes a packet body (without its opcode prefix) into its wire form.
+pub trait IntoBytes {
+    fn into_bytes(self) -> Vec<u8>;
+}
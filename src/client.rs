@@ -0,0 +1,211 @@
+use std::convert::TryFrom;
+use std::io::{self, ErrorKind, Result};
+use std::net::{SocketAddr, ToSocketAddrs, UdpSocket};
+use std::time::Duration;
+
+use crate::bytes::FromBytes;
+use crate::packet::{
+    self, Ack, Data, ErrorCode, ErrorPacket, Mode, Oack, Opcode, Options, Rrq, DEFAULT_BLKSIZE, MAX_BLKSIZE,
+    MIN_BLKSIZE,
+};
+
+const MAX_PACKET_SIZE: usize = 65536;
+
+/// Configures and creates a `Client` connected to a single TFTP server.
+pub struct Builder {
+    socket: UdpSocket,
+    server_addr: Option<SocketAddr>,
+    blksize: Option<u16>,
+    timeout: Option<Duration>,
+    tsize: bool,
+}
+
+impl Builder {
+    pub fn new() -> Result<Self> {
+        let socket = UdpSocket::bind("0.0.0.0:0")?;
+
+        Ok(Self {
+            socket,
+            server_addr: None,
+            blksize: None,
+            timeout: None,
+            tsize: false,
+        })
+    }
+
+    pub fn connect_to<A: ToSocketAddrs>(mut self, addr: A) -> Result<Self> {
+        let addr = addr
+            .to_socket_addrs()?
+            .next()
+            .ok_or_else(|| -> io::Error { ErrorKind::InvalidInput.into() })?;
+
+        self.server_addr = Some(addr);
+        Ok(self)
+    }
+
+    /// Requests the given `blksize` (RFC 2348) during negotiation.
+    pub fn blksize(mut self, blksize: u16) -> Self {
+        self.blksize = Some(blksize);
+        self
+    }
+
+    /// Requests the given `timeout`, in whole seconds (RFC 2349), during
+    /// negotiation.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Requests `tsize` (RFC 2349) during negotiation.
+    pub fn tsize(mut self, tsize: bool) -> Self {
+        self.tsize = tsize;
+        self
+    }
+
+    pub fn build(self) -> Client {
+        Client {
+            socket: self.socket,
+            server_addr: self.server_addr.expect("connect_to must be called before build"),
+            blksize: self.blksize,
+            timeout: self.timeout,
+            tsize: self.tsize,
+        }
+    }
+}
+
+/// A TFTP client connected to a single server.
+pub struct Client {
+    socket: UdpSocket,
+    server_addr: SocketAddr,
+    blksize: Option<u16>,
+    timeout: Option<Duration>,
+    tsize: bool,
+}
+
+impl Client {
+    fn requested_options(&self) -> Options {
+        let mut options = Options::new();
+
+        if let Some(blksize) = self.blksize {
+            options.push(("blksize".to_string(), blksize.to_string()));
+        }
+
+        if let Some(timeout) = self.timeout {
+            options.push(("timeout".to_string(), timeout.as_secs().to_string()));
+        }
+
+        if self.tsize {
+            options.push(("tsize".to_string(), "0".to_string()));
+        }
+
+        options
+    }
+
+    /// Downloads `filename` from the server, appending its contents to
+    /// `buf` and returning it.
+    ///
+    /// If the server refuses our requested options outright (an `Error`
+    /// reply with `ErrorCode::OptionNegotiationFailed`, before any `Data`
+    /// arrives), this falls back to a single plain transfer with no
+    /// options and the default 512-byte blocks, matching how an
+    /// RFC-2347-unaware server would already have been handled.
+    pub fn get(&self, filename: &str, mode: Mode, buf: Vec<u8>) -> Result<Vec<u8>> {
+        let requested = self.requested_options();
+
+        if requested.is_empty() {
+            return self.transfer(filename, mode, requested, buf);
+        }
+
+        match self.transfer(filename, mode, requested, buf.clone()) {
+            Err(e) if e.kind() == ErrorKind::Unsupported => self.transfer(filename, mode, Options::new(), buf),
+            result => result,
+        }
+    }
+
+    fn transfer(&self, filename: &str, mode: Mode, options: Options, mut buf: Vec<u8>) -> Result<Vec<u8>> {
+        let rrq = Rrq::with_options(filename, mode, options.clone());
+
+        self.socket.send_to(&packet::frame(rrq), self.server_addr)?;
+
+        let mut blksize = DEFAULT_BLKSIZE;
+        let mut expecting_oack = !options.is_empty();
+        let mut block_expected: u16 = 1;
+
+        loop {
+            let mut recv_buf = vec![0u8; MAX_PACKET_SIZE];
+            let (len, peer) = self.socket.recv_from(&mut recv_buf)?;
+            recv_buf.truncate(len);
+
+            if recv_buf.len() < 2 {
+                return Err(ErrorKind::InvalidData.into());
+            }
+
+            let opcode = Opcode::try_from(u16::from_be_bytes([recv_buf[0], recv_buf[1]]))?;
+
+            match opcode {
+                Opcode::Oack if expecting_oack => {
+                    let oack = Oack::from_bytes(&recv_buf[2..])?;
+
+                    for (option, value) in oack.options() {
+                        if option.eq_ignore_ascii_case("blksize") {
+                            let negotiated = value
+                                .parse::<u16>()
+                                .ok()
+                                .filter(|b| (MIN_BLKSIZE..=MAX_BLKSIZE).contains(b));
+
+                            let negotiated = match negotiated {
+                                Some(negotiated) => negotiated,
+                                None => {
+                                    self.socket.send_to(
+                                        &packet::frame(ErrorPacket::new(
+                                            ErrorCode::OptionNegotiationFailed,
+                                            "blksize value out of range",
+                                        )),
+                                        peer,
+                                    )?;
+                                    return Err(ErrorKind::InvalidData.into());
+                                }
+                            };
+
+                            blksize = negotiated;
+                        }
+                    }
+
+                    self.socket.send_to(&packet::frame(Ack { block: 0 }), peer)?;
+                    expecting_oack = false;
+                }
+                Opcode::Data => {
+                    expecting_oack = false;
+
+                    let data = Data::from_bytes(&recv_buf[2..])?;
+
+                    if data.block != block_expected {
+                        return Err(ErrorKind::InvalidData.into());
+                    }
+
+                    let is_last = data.payload.len() < blksize as usize;
+                    buf.extend_from_slice(&data.payload);
+
+                    self.socket.send_to(&packet::frame(Ack { block: data.block }), peer)?;
+                    block_expected = block_expected.wrapping_add(1);
+
+                    if is_last {
+                        break;
+                    }
+                }
+                Opcode::Error => {
+                    let error = ErrorPacket::from_bytes(&recv_buf[2..])?;
+
+                    if expecting_oack && error.code == ErrorCode::OptionNegotiationFailed {
+                        return Err(ErrorKind::Unsupported.into());
+                    }
+
+                    return Err(ErrorKind::Other.into());
+                }
+                _ => return Err(ErrorKind::InvalidData.into()),
+            }
+        }
+
+        Ok(buf)
+    }
+}
@@ -0,0 +1,7 @@
+pub mod bytes;
+pub mod client;
+pub mod packet;
+mod path;
+pub mod server;
+
+pub use server::Server;
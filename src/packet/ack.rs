@@ -0,0 +1,42 @@
+use tftp_wire_derive::{FromBytes, IntoBytes};
+
+use crate::packet::opcode::Opcode;
+use crate::packet::sealed::Packet;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, FromBytes, IntoBytes)]
+pub struct Ack {
+    #[wire(u16)]
+    pub block: u16,
+}
+
+impl Packet for Ack {
+    const OPCODE: Opcode = Opcode::Ack;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bytes::{FromBytes, IntoBytes};
+    use crate::packet::sealed::Packet;
+
+    #[test]
+    fn test_opcode() {
+        assert_eq!(Ack::OPCODE, Opcode::Ack);
+    }
+
+    #[test]
+    fn test_ack_from_bytes() {
+        let ack = Ack::from_bytes([0, 7]).unwrap();
+        assert_eq!(ack.block, 7);
+    }
+
+    #[test]
+    fn test_ack_into_bytes() {
+        assert_eq!(Ack { block: 7 }.into_bytes(), vec![0, 7]);
+    }
+
+    #[test]
+    fn test_ack_rejects_truncated_bytes() {
+        assert!(Ack::from_bytes([0u8; 1]).is_err());
+    }
+}
@@ -0,0 +1,58 @@
+use tftp_wire_derive::{FromBytes, IntoBytes};
+
+use crate::packet::opcode::Opcode;
+use crate::packet::sealed::Packet;
+
+#[derive(Clone, Debug, Eq, PartialEq, FromBytes, IntoBytes)]
+pub struct Data {
+    #[wire(u16)]
+    pub block: u16,
+    #[wire(bytes)]
+    pub payload: Vec<u8>,
+}
+
+impl Packet for Data {
+    const OPCODE: Opcode = Opcode::Data;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bytes::{FromBytes, IntoBytes};
+    use crate::packet::sealed::Packet;
+
+    #[test]
+    fn test_opcode() {
+        assert_eq!(Data::OPCODE, Opcode::Data);
+    }
+
+    #[test]
+    fn test_data_from_bytes() {
+        let bytes = [0, 1, b'h', b'i'];
+        let data = Data::from_bytes(bytes).unwrap();
+
+        assert_eq!(data.block, 1);
+        assert_eq!(data.payload, b"hi".to_vec());
+    }
+
+    #[test]
+    fn test_data_into_bytes() {
+        let data = Data {
+            block: 2,
+            payload: b"hi".to_vec(),
+        };
+
+        assert_eq!(data.into_bytes(), vec![0, 2, b'h', b'i']);
+    }
+
+    #[test]
+    fn test_data_round_trip_empty_payload() {
+        let data = Data {
+            block: 65535,
+            payload: vec![],
+        };
+
+        let bytes = data.clone().into_bytes();
+        assert_eq!(Data::from_bytes(bytes).unwrap(), data);
+    }
+}
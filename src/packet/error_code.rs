@@ -0,0 +1,12 @@
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ErrorCode {
+    NotDefined = 0,
+    FileNotFound = 1,
+    AccessViolation = 2,
+    DiskFull = 3,
+    IllegalOperation = 4,
+    UnknownTid = 5,
+    FileAlreadyExists = 6,
+    NoSuchUser = 7,
+    OptionNegotiationFailed = 8,
+}
@@ -0,0 +1,56 @@
+use tftp_wire_derive::{FromBytes, IntoBytes};
+
+use crate::packet::error_code::ErrorCode;
+use crate::packet::opcode::Opcode;
+use crate::packet::sealed::Packet;
+
+/// The `Error` packet (opcode 5): an `ErrorCode` plus a human-readable,
+/// NUL-terminated message.
+#[derive(Clone, Debug, Eq, PartialEq, FromBytes, IntoBytes)]
+pub struct ErrorPacket {
+    #[wire(u16)]
+    pub code: ErrorCode,
+    #[wire(cstr)]
+    pub message: String,
+}
+
+impl ErrorPacket {
+    pub fn new<T: Into<String>>(code: ErrorCode, message: T) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+impl Packet for ErrorPacket {
+    const OPCODE: Opcode = Opcode::Error;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bytes::{FromBytes, IntoBytes};
+    use crate::packet::sealed::Packet;
+
+    #[test]
+    fn test_opcode() {
+        assert_eq!(ErrorPacket::OPCODE, Opcode::Error);
+    }
+
+    #[test]
+    fn test_error_packet_round_trip() {
+        let err = ErrorPacket::new(ErrorCode::AccessViolation, "permission denied");
+
+        let bytes = err.clone().into_bytes();
+        assert_eq!(ErrorPacket::from_bytes(bytes).unwrap(), err);
+    }
+
+    #[test]
+    fn test_error_packet_rejects_missing_nul() {
+        let mut bytes = u16::from(ErrorCode::NotDefined).to_be_bytes().to_vec();
+        bytes.extend_from_slice(b"no terminator");
+
+        assert!(ErrorPacket::from_bytes(bytes).is_err());
+    }
+}
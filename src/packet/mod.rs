@@ -0,0 +1,30 @@
+mod bytes;
+mod sealed;
+
+pub mod ack;
+pub mod data;
+pub mod error_code;
+pub mod error_packet;
+pub mod mode;
+pub mod oack;
+pub mod opcode;
+pub mod options;
+pub mod rq;
+
+pub use ack::Ack;
+pub use data::Data;
+pub use error_code::ErrorCode;
+pub use error_packet::ErrorPacket;
+pub use mode::Mode;
+pub use oack::Oack;
+pub use opcode::Opcode;
+pub use options::{Options, DEFAULT_BLKSIZE, MAX_BLKSIZE, MAX_TIMEOUT, MIN_BLKSIZE, MIN_TIMEOUT};
+pub use rq::{Rq, Rrq, Wrq};
+
+/// Prepends a packet's 2-byte opcode (via the sealed `Packet` trait) to its
+/// encoded body, producing the full wire form ready to send.
+pub(crate) fn frame<P: sealed::Packet + crate::bytes::IntoBytes>(packet: P) -> Vec<u8> {
+    let mut bytes = u16::from(P::OPCODE).to_be_bytes().to_vec();
+    bytes.append(&mut packet.into_bytes());
+    bytes
+}
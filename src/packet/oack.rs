@@ -0,0 +1,47 @@
+use tftp_wire_derive::{FromBytes, IntoBytes};
+
+use crate::packet::opcode::Opcode;
+use crate::packet::options::Options;
+use crate::packet::sealed::Packet;
+
+/// The option-acknowledgement reply (RFC 2347) a server sends back with
+/// only the `(option, value)` pairs it actually accepted.
+#[derive(Clone, Debug, Eq, PartialEq, FromBytes, IntoBytes)]
+pub struct Oack {
+    #[wire(options)]
+    options: Options,
+}
+
+impl Oack {
+    pub fn new(options: Options) -> Self {
+        Self { options }
+    }
+
+    pub fn options(&self) -> &Options {
+        &self.options
+    }
+}
+
+impl Packet for Oack {
+    const OPCODE: Opcode = Opcode::Oack;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bytes::{FromBytes, IntoBytes};
+    use crate::packet::sealed::Packet;
+
+    #[test]
+    fn test_opcode() {
+        assert_eq!(Oack::OPCODE, Opcode::Oack);
+    }
+
+    #[test]
+    fn test_oack_round_trip() {
+        let oack = Oack::new(vec![("blksize".to_string(), "1024".to_string())]);
+
+        let bytes = oack.clone().into_bytes();
+        assert_eq!(Oack::from_bytes(bytes).unwrap(), oack);
+    }
+}
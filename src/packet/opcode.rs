@@ -0,0 +1,9 @@
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Opcode {
+    Rrq = 1,
+    Wrq = 2,
+    Data = 3,
+    Ack = 4,
+    Error = 5,
+    Oack = 6,
+}
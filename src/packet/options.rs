@@ -0,0 +1,109 @@
+use std::io::{self, ErrorKind, Result};
+
+/// An ordered `(option, value)` pair as negotiated by RFC 2347/2348/2349
+/// (`blksize`, `timeout`, `tsize`). These trail an RRQ/WRQ after the mode
+/// field, each a NUL-terminated key followed by a NUL-terminated value,
+/// using the same framing the filename and mode already use.
+pub type Options = Vec<(String, String)>;
+
+/// The block size (RFC 2348) used when `blksize` isn't negotiated.
+pub const DEFAULT_BLKSIZE: u16 = 512;
+/// RFC 2348's allowed `blksize` range; client and server both clamp/reject
+/// to this so they agree on what's negotiable.
+pub const MIN_BLKSIZE: u16 = 8;
+pub const MAX_BLKSIZE: u16 = 65464;
+/// RFC 2349's allowed `timeout` range, in whole seconds.
+pub const MIN_TIMEOUT: u8 = 1;
+pub const MAX_TIMEOUT: u8 = 255;
+
+/// Parses the trailing `(option, value)` pairs of an RRQ/WRQ body. An empty
+/// slice parses to an empty `Options`, so requests with no options parse
+/// exactly as before.
+pub fn parse(mut bytes: &[u8]) -> Result<Options> {
+    let mut options = Options::new();
+
+    while !bytes.is_empty() {
+        let (option, rest) = take_cstr(bytes)?;
+        let (value, rest) = take_cstr(rest)?;
+
+        options.push((option, value));
+        bytes = rest;
+    }
+
+    Ok(options)
+}
+
+/// Encodes `(option, value)` pairs back into their NUL-terminated wire
+/// form, in the same order they were given.
+pub fn encode(options: &Options) -> Vec<u8> {
+    let mut bytes = vec![];
+
+    for (option, value) in options {
+        bytes.extend_from_slice(option.as_bytes());
+        bytes.push(0);
+        bytes.extend_from_slice(value.as_bytes());
+        bytes.push(0);
+    }
+
+    bytes
+}
+
+fn take_cstr(bytes: &[u8]) -> Result<(String, &[u8])> {
+    let nul = match bytes.iter().position(|b| *b == 0) {
+        Some(n) => n,
+        None => return Err(ErrorKind::InvalidInput.into()),
+    };
+
+    let s = String::from_utf8(bytes[..nul].to_vec())
+        .map_err(|_| -> io::Error { ErrorKind::InvalidInput.into() })?;
+
+    Ok((s, &bytes[nul + 1..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_empty() {
+        assert_eq!(parse(&[]).unwrap(), Options::new());
+    }
+
+    #[test]
+    fn test_parse_single_option() {
+        let bytes = b"blksize\x001024\0";
+        let options = parse(bytes).unwrap();
+        assert_eq!(options, vec![("blksize".to_string(), "1024".to_string())]);
+    }
+
+    #[test]
+    fn test_parse_multiple_options_preserves_order() {
+        let bytes = b"blksize\x001024\0timeout\x005\0tsize\x000\0";
+        let options = parse(bytes).unwrap();
+        assert_eq!(
+            options,
+            vec![
+                ("blksize".to_string(), "1024".to_string()),
+                ("timeout".to_string(), "5".to_string()),
+                ("tsize".to_string(), "0".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_missing_nul_is_invalid() {
+        assert!(parse(b"blksize").is_err());
+        assert!(parse(b"blksize\x001024").is_err());
+    }
+
+    #[test]
+    fn test_encode_round_trips_through_parse() {
+        let options = vec![
+            ("blksize".to_string(), "1024".to_string()),
+            ("tsize".to_string(), "12345".to_string()),
+        ];
+
+        let bytes = encode(&options);
+        assert_eq!(parse(&bytes).unwrap(), options);
+    }
+}
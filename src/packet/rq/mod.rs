@@ -0,0 +1,80 @@
+use tftp_wire_derive::{FromBytes, IntoBytes};
+
+use crate::packet::mode::Mode;
+use crate::packet::options::Options;
+
+mod rrq;
+mod wrq;
+
+pub use rrq::Rrq;
+pub use wrq::Wrq;
+
+/// The shared filename/mode/options body of an RRQ or WRQ, appended after
+/// the opcode and wrapped by `Rrq`/`Wrq` respectively.
+#[derive(Clone, Debug, Eq, PartialEq, FromBytes, IntoBytes)]
+pub struct Rq {
+    #[wire(cstr)]
+    pub filename: String,
+    #[wire(cstr)]
+    pub mode: Mode,
+    #[wire(options)]
+    pub options: Options,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bytes::{FromBytes, IntoBytes};
+
+    #[test]
+    fn test_rq_from_bytes() {
+        let bytes = vec![b'h', b'i', b'.', b't', b'x', b't', b'\0', b'n', b'e', b't', b'a', b's', b'c', b'i', b'i', b'\0'];
+        let rq = Rq::from_bytes(bytes).unwrap();
+
+        assert_eq!(rq.filename, "hi.txt".to_string());
+        assert_eq!(rq.mode, Mode::NetAscii);
+        assert_eq!(rq.options, Vec::new());
+    }
+
+    #[test]
+    fn test_rq_to_bytes() {
+        let rq = Rq {
+            filename: "bye.txt".to_string(),
+            mode: Mode::Mail,
+            options: Vec::new(),
+        };
+
+        let bytes = rq.into_bytes();
+        assert_eq!(bytes, vec![b'b', b'y', b'e', b'.', b't', b'x', b't', b'\0', b'm', b'a', b'i', b'l', b'\0']);
+    }
+
+    #[test]
+    fn test_rq_from_bytes_with_options() {
+        let mut bytes = b"hi.txt\0octet\0".to_vec();
+        bytes.extend_from_slice(b"blksize\x001024\0tsize\x000\0");
+
+        let rq = Rq::from_bytes(bytes).unwrap();
+
+        assert_eq!(rq.filename, "hi.txt".to_string());
+        assert_eq!(rq.mode, Mode::Octet);
+        assert_eq!(
+            rq.options,
+            vec![
+                ("blksize".to_string(), "1024".to_string()),
+                ("tsize".to_string(), "0".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rq_options_round_trip() {
+        let rq = Rq {
+            filename: "hi.txt".to_string(),
+            mode: Mode::Octet,
+            options: vec![("blksize".to_string(), "1024".to_string())],
+        };
+
+        let bytes = rq.clone().into_bytes();
+        assert_eq!(Rq::from_bytes(bytes).unwrap(), rq);
+    }
+}
@@ -0,0 +1,80 @@
+use std::io::{self, Result};
+
+use crate::bytes::{FromBytes, IntoBytes};
+use crate::packet::mode::Mode;
+use crate::packet::opcode::Opcode;
+use crate::packet::options::Options;
+use crate::packet::sealed::Packet;
+use super::Rq;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Rrq(Rq);
+
+impl Rrq {
+    pub fn new<T: AsRef<str>>(filename: T, mode: Mode) -> Self {
+        Self::with_options(filename, mode, Options::new())
+    }
+
+    pub fn with_options<T: AsRef<str>>(filename: T, mode: Mode, options: Options) -> Self {
+        let filename = filename.as_ref().to_string();
+        Self(Rq {
+            filename,
+            mode,
+            options,
+        })
+    }
+
+    pub fn filename(&self) -> &str {
+        &self.0.filename
+    }
+
+    pub fn mode(&self) -> Mode {
+        self.0.mode
+    }
+
+    pub fn options(&self) -> &Options {
+        &self.0.options
+    }
+}
+
+impl Packet for Rrq {
+    const OPCODE: Opcode = Opcode::Rrq;
+}
+
+impl FromBytes for Rrq {
+    type Error = io::Error;
+
+    fn from_bytes<T: AsRef<[u8]>>(bytes: T) -> Result<Self> {
+        let rq = Rq::from_bytes(bytes)?;
+
+        Ok(Self(rq))
+    }
+}
+
+impl IntoBytes for Rrq {
+    fn into_bytes(self) -> Vec<u8> {
+        self.0.into_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_opcode() {
+        assert_eq!(Rrq::OPCODE, Opcode::Rrq);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let rrq = Rrq::with_options(
+            "hi.txt",
+            Mode::Octet,
+            vec![("blksize".to_string(), "1024".to_string())],
+        );
+
+        let bytes = rrq.clone().into_bytes();
+        assert_eq!(Rrq::from_bytes(bytes).unwrap(), rrq);
+    }
+}
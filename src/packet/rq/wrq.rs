@@ -3,6 +3,7 @@ use std::io::{self, Result};
 use crate::bytes::{FromBytes, IntoBytes};
 use crate::packet::mode::Mode;
 use crate::packet::opcode::Opcode;
+use crate::packet::options::Options;
 use crate::packet::sealed::Packet;
 use super::Rq;
 
@@ -11,8 +12,28 @@ pub struct Wrq(Rq);
 
 impl Wrq {
     pub fn new<T: AsRef<str>>(filename: T, mode: Mode) -> Self {
+        Self::with_options(filename, mode, Options::new())
+    }
+
+    pub fn with_options<T: AsRef<str>>(filename: T, mode: Mode, options: Options) -> Self {
         let filename = filename.as_ref().to_string();
-        Self(Rq { filename, mode })
+        Self(Rq {
+            filename,
+            mode,
+            options,
+        })
+    }
+
+    pub fn filename(&self) -> &str {
+        &self.0.filename
+    }
+
+    pub fn mode(&self) -> Mode {
+        self.0.mode
+    }
+
+    pub fn options(&self) -> &Options {
+        &self.0.options
     }
 }
 
@@ -35,3 +56,21 @@ impl IntoBytes for Wrq {
         self.0.into_bytes()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_opcode() {
+        assert_eq!(Wrq::OPCODE, Opcode::Wrq);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let wrq = Wrq::new("hi.txt", Mode::Octet);
+
+        let bytes = wrq.clone().into_bytes();
+        assert_eq!(Wrq::from_bytes(bytes).unwrap(), wrq);
+    }
+}
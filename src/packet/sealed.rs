@@ -0,0 +1,7 @@
+use super::Opcode;
+
+/// Associates a packet type with the opcode it is framed under. Kept in a
+/// private module so only this crate can name packet types.
+pub trait Packet {
+    const OPCODE: Opcode;
+}
@@ -0,0 +1,132 @@
+use std::ffi::OsString;
+use std::io::{self, ErrorKind, Result};
+use std::path::{Component, Path, PathBuf};
+
+/// Resolves a client-supplied filename against a server's serve directory,
+/// rejecting anything that would escape it via `../` traversal, an absolute
+/// path, or a symlink pointing outside the root.
+///
+/// `root` must already exist. `requested` does not need to (e.g. for a
+/// `put`, which creates the file), so any trailing path components that
+/// don't exist yet are canonicalized against their nearest existing
+/// ancestor instead of failing outright.
+pub fn resolve_in(root: &Path, requested: &str) -> Result<PathBuf> {
+    let root = root.canonicalize()?;
+    let mut joined = root.clone();
+
+    // Reject `..`/root/prefix components lexically before ever touching the
+    // filesystem — canonicalize_partial only resolves symlinks for
+    // components that already exist, so a literal `..` past a not-yet-
+    // existing directory would otherwise survive into the final path and
+    // let the OS walk it straight out of `root`.
+    for component in Path::new(requested).components() {
+        match component {
+            Component::Normal(part) => joined.push(part),
+            Component::CurDir => {}
+            Component::ParentDir | Component::RootDir | Component::Prefix(_) => {
+                return Err(ErrorKind::PermissionDenied.into());
+            }
+        }
+    }
+
+    let resolved = canonicalize_partial(&joined)?;
+
+    if resolved.starts_with(&root) {
+        Ok(resolved)
+    } else {
+        Err(ErrorKind::PermissionDenied.into())
+    }
+}
+
+/// Canonicalizes `path`, walking up to the nearest existing ancestor and
+/// re-appending the non-existent tail if `path` itself doesn't exist yet.
+///
+/// Iterative rather than recursive: `path` comes from a client-supplied
+/// filename, and a request with enough nonexistent path segments would
+/// otherwise recurse once per segment and blow the stack.
+fn canonicalize_partial(path: &Path) -> Result<PathBuf> {
+    let mut tail = Vec::new();
+    let mut ancestor = path;
+
+    loop {
+        if let Ok(resolved) = ancestor.canonicalize() {
+            let mut resolved = resolved;
+            for file_name in tail.into_iter().rev() {
+                resolved.push(file_name);
+            }
+            return Ok(resolved);
+        }
+
+        let file_name = ancestor
+            .file_name()
+            .map(OsString::from)
+            .ok_or_else(|| -> io::Error { ErrorKind::InvalidInput.into() })?;
+        let parent = ancestor
+            .parent()
+            .ok_or_else(|| -> io::Error { ErrorKind::InvalidInput.into() })?;
+
+        tail.push(file_name);
+        ancestor = parent;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let mut dir = std::env::temp_dir();
+        dir.push(format!("tftp-path-tests-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_resolve_existing_file_inside_root() {
+        let root = scratch_dir("existing");
+        fs::write(root.join("hi.txt"), b"hi").unwrap();
+
+        let resolved = resolve_in(&root, "hi.txt").unwrap();
+        assert_eq!(resolved, root.canonicalize().unwrap().join("hi.txt"));
+    }
+
+    #[test]
+    fn test_resolve_new_file_inside_root() {
+        let root = scratch_dir("new-file");
+
+        let resolved = resolve_in(&root, "put-me.txt").unwrap();
+        assert_eq!(resolved, root.canonicalize().unwrap().join("put-me.txt"));
+    }
+
+    #[test]
+    fn test_resolve_rejects_parent_traversal() {
+        let root = scratch_dir("traversal");
+
+        assert!(resolve_in(&root, "../../etc/passwd").is_err());
+    }
+
+    #[test]
+    fn test_resolve_rejects_traversal_through_nonexistent_dir() {
+        let root = scratch_dir("traversal-nonexistent");
+
+        assert!(resolve_in(&root, "nosuchdir/../../../../tmp/evil.txt").is_err());
+    }
+
+    #[test]
+    fn test_resolve_rejects_absolute_path_outside_root() {
+        let root = scratch_dir("absolute");
+
+        assert!(resolve_in(&root, "/etc/passwd").is_err());
+    }
+
+    #[test]
+    fn test_resolve_handles_deeply_nested_nonexistent_path() {
+        let root = scratch_dir("deep-nonexistent");
+
+        let deep = "a/".repeat(50_000) + "put-me.txt";
+        let resolved = resolve_in(&root, &deep).unwrap();
+        assert!(resolved.starts_with(root.canonicalize().unwrap()));
+    }
+}
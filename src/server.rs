@@ -0,0 +1,285 @@
+use std::convert::TryFrom;
+use std::fs;
+use std::io::{ErrorKind, Result};
+use std::net::{SocketAddr, ToSocketAddrs, UdpSocket};
+use std::path::{Path, PathBuf};
+
+use crate::bytes::FromBytes;
+use crate::packet::{
+    self, Ack, Data, ErrorCode, ErrorPacket, Oack, Opcode, Options, Rrq, Wrq, DEFAULT_BLKSIZE, MAX_BLKSIZE,
+    MAX_TIMEOUT, MIN_BLKSIZE, MIN_TIMEOUT,
+};
+use crate::path;
+
+const MAX_PACKET_SIZE: usize = 65536;
+
+/// A TFTP server bound to a local address, serving files out of a
+/// canonicalized root directory.
+pub struct Server {
+    socket: UdpSocket,
+    root: PathBuf,
+}
+
+impl Server {
+    pub fn new<A: ToSocketAddrs, P: AsRef<Path>>(addr: A, dir: P) -> Result<Self> {
+        let socket = UdpSocket::bind(addr)?;
+        let root = dir.as_ref().canonicalize()?;
+
+        Ok(Self { socket, root })
+    }
+
+    /// Consumes the `Server`, handing off its socket and serve directory to
+    /// a `Handler` that does the actual request processing.
+    pub fn serve(self) -> Result<Handler> {
+        Ok(Handler {
+            socket: self.socket,
+            root: self.root,
+        })
+    }
+}
+
+/// Processes TFTP requests received on the `Server`'s socket.
+pub struct Handler {
+    socket: UdpSocket,
+    root: PathBuf,
+}
+
+impl Handler {
+    /// Waits for a single RRQ or WRQ and serves it to completion.
+    pub fn handle(self) -> Result<()> {
+        let mut buf = vec![0u8; MAX_PACKET_SIZE];
+        let (len, peer) = self.socket.recv_from(&mut buf)?;
+        buf.truncate(len);
+
+        if buf.len() < 2 {
+            return Err(ErrorKind::InvalidData.into());
+        }
+
+        let opcode = Opcode::try_from(u16::from_be_bytes([buf[0], buf[1]]))?;
+        let body = &buf[2..];
+
+        match opcode {
+            Opcode::Rrq => self.handle_rrq(body, peer),
+            Opcode::Wrq => self.handle_wrq(body, peer),
+            _ => Err(ErrorKind::InvalidData.into()),
+        }
+    }
+
+    fn handle_rrq(&self, body: &[u8], peer: SocketAddr) -> Result<()> {
+        let rrq = match Rrq::from_bytes(body) {
+            Ok(rrq) => rrq,
+            Err(e) => {
+                send_error(&self.socket, peer, ErrorCode::IllegalOperation, "malformed request")?;
+                return Err(e);
+            }
+        };
+
+        let path = match path::resolve_in(&self.root, rrq.filename()) {
+            Ok(path) => path,
+            Err(_) => {
+                send_error(&self.socket, peer, ErrorCode::AccessViolation, "permission denied")?;
+                return Err(ErrorKind::PermissionDenied.into());
+            }
+        };
+
+        let data = match fs::read(&path) {
+            Ok(data) => data,
+            Err(_) => {
+                send_error(&self.socket, peer, ErrorCode::FileNotFound, "file not found")?;
+                return Err(ErrorKind::NotFound.into());
+            }
+        };
+
+        let transfer_socket = UdpSocket::bind("0.0.0.0:0")?;
+        let (blksize, reply) = negotiate_or_reject(&transfer_socket, peer, rrq.options(), data.len())?;
+
+        if !reply.is_empty() {
+            transfer_socket.send_to(&packet::frame(Oack::new(reply)), peer)?;
+            expect_ack(&transfer_socket, peer, 0)?;
+        }
+
+        let mut block: u16 = 1;
+        let mut offset = 0;
+
+        loop {
+            let end = std::cmp::min(offset + blksize as usize, data.len());
+            let chunk = &data[offset..end];
+
+            let data_packet = Data {
+                block,
+                payload: chunk.to_vec(),
+            };
+            transfer_socket.send_to(&packet::frame(data_packet), peer)?;
+            expect_ack(&transfer_socket, peer, block)?;
+
+            offset = end;
+            let is_last = chunk.len() < blksize as usize;
+            block = block.wrapping_add(1);
+
+            if is_last {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn handle_wrq(&self, body: &[u8], peer: SocketAddr) -> Result<()> {
+        let wrq = match Wrq::from_bytes(body) {
+            Ok(wrq) => wrq,
+            Err(e) => {
+                send_error(&self.socket, peer, ErrorCode::IllegalOperation, "malformed request")?;
+                return Err(e);
+            }
+        };
+
+        let path = match path::resolve_in(&self.root, wrq.filename()) {
+            Ok(path) => path,
+            Err(_) => {
+                send_error(&self.socket, peer, ErrorCode::AccessViolation, "permission denied")?;
+                return Err(ErrorKind::PermissionDenied.into());
+            }
+        };
+
+        let transfer_socket = UdpSocket::bind("0.0.0.0:0")?;
+        let (blksize, reply) = negotiate_or_reject(&transfer_socket, peer, wrq.options(), 0)?;
+
+        if reply.is_empty() {
+            transfer_socket.send_to(&packet::frame(Ack { block: 0 }), peer)?;
+        } else {
+            transfer_socket.send_to(&packet::frame(Oack::new(reply)), peer)?;
+        }
+
+        let mut contents = Vec::new();
+        let mut expected_block: u16 = 1;
+
+        loop {
+            let mut buf = vec![0u8; MAX_PACKET_SIZE];
+            let (len, from) = transfer_socket.recv_from(&mut buf)?;
+
+            if from != peer {
+                continue;
+            }
+
+            buf.truncate(len);
+
+            if buf.len() < 2 {
+                return Err(ErrorKind::InvalidData.into());
+            }
+
+            let opcode = Opcode::try_from(u16::from_be_bytes([buf[0], buf[1]]))?;
+
+            if opcode != Opcode::Data {
+                return Err(ErrorKind::InvalidData.into());
+            }
+
+            let data = Data::from_bytes(&buf[2..])?;
+
+            if data.block != expected_block {
+                return Err(ErrorKind::InvalidData.into());
+            }
+
+            let is_last = data.payload.len() < blksize as usize;
+            let block = data.block;
+            contents.extend_from_slice(&data.payload);
+
+            transfer_socket.send_to(&packet::frame(Ack { block }), peer)?;
+            expected_block = expected_block.wrapping_add(1);
+
+            if is_last {
+                break;
+            }
+        }
+
+        fs::write(&path, &contents)
+    }
+}
+
+/// Negotiates the options an RRQ/WRQ requested, returning the agreed
+/// `blksize` and the `(option, value)` pairs to echo back in an OACK.
+///
+/// A recognized option with a value that doesn't parse, or a `timeout`
+/// outside RFC 2349's 1-255s range, fails the whole negotiation (the
+/// caller reports it as `ErrorCode::OptionNegotiationFailed`) rather than
+/// being silently dropped — unlike `blksize` out of protocol range, which
+/// is just clamped, a malformed value means the peer's negotiation logic
+/// itself is broken and continuing would only paper over that.
+fn negotiate_options(requested: &Options, file_size: usize) -> std::result::Result<(u16, Options), &'static str> {
+    let mut blksize = DEFAULT_BLKSIZE;
+    let mut reply = Options::new();
+
+    for (option, value) in requested {
+        match option.to_ascii_lowercase().as_str() {
+            "blksize" => {
+                let requested_blksize: u16 = value.parse().map_err(|_| "blksize option value is not a number")?;
+                blksize = requested_blksize.clamp(MIN_BLKSIZE, MAX_BLKSIZE);
+                reply.push(("blksize".to_string(), blksize.to_string()));
+            }
+            "tsize" => reply.push(("tsize".to_string(), file_size.to_string())),
+            "timeout" => {
+                let timeout: u8 = value
+                    .parse()
+                    .ok()
+                    .filter(|t| (MIN_TIMEOUT..=MAX_TIMEOUT).contains(t))
+                    .ok_or("timeout option value must be between 1 and 255")?;
+                reply.push(("timeout".to_string(), timeout.to_string()));
+            }
+            _ => {}
+        }
+    }
+
+    Ok((blksize, reply))
+}
+
+/// Runs `negotiate_options` and, on failure, reports it to the peer as
+/// `ErrorCode::OptionNegotiationFailed` before bubbling the error up —
+/// shared by `handle_rrq` and `handle_wrq` so the reporting stays in sync.
+fn negotiate_or_reject(
+    socket: &UdpSocket,
+    peer: SocketAddr,
+    requested: &Options,
+    file_size: usize,
+) -> Result<(u16, Options)> {
+    match negotiate_options(requested, file_size) {
+        Ok(negotiated) => Ok(negotiated),
+        Err(message) => {
+            send_error(socket, peer, ErrorCode::OptionNegotiationFailed, message)?;
+            Err(ErrorKind::InvalidInput.into())
+        }
+    }
+}
+
+fn send_error(socket: &UdpSocket, peer: SocketAddr, code: ErrorCode, message: &str) -> Result<()> {
+    socket.send_to(&packet::frame(ErrorPacket::new(code, message)), peer)?;
+    Ok(())
+}
+
+fn expect_ack(socket: &UdpSocket, peer: SocketAddr, block: u16) -> Result<()> {
+    let mut buf = vec![0u8; MAX_PACKET_SIZE];
+
+    loop {
+        let (len, from) = socket.recv_from(&mut buf)?;
+
+        if from != peer {
+            continue;
+        }
+
+        if len < 2 {
+            return Err(ErrorKind::InvalidData.into());
+        }
+
+        let opcode = Opcode::try_from(u16::from_be_bytes([buf[0], buf[1]]))?;
+
+        if opcode != Opcode::Ack {
+            return Err(ErrorKind::InvalidData.into());
+        }
+
+        let ack = Ack::from_bytes(&buf[2..len])?;
+
+        if ack.block != block {
+            return Err(ErrorKind::InvalidData.into());
+        }
+
+        return Ok(());
+    }
+}
@@ -0,0 +1,64 @@
+use std::net::UdpSocket;
+use std::thread;
+use std::time::Duration;
+
+use tftp::client;
+use tftp::packet::Mode;
+
+#[test]
+fn test_get_falls_back_to_default_blksize_when_oack_omits_it() {
+    let fake_server = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let fake_server_addr = fake_server.local_addr().unwrap();
+    fake_server.set_read_timeout(Some(Duration::from_secs(2))).unwrap();
+
+    // A real 512-byte block plus a short final block: if the client wrongly
+    // kept its requested (and ungranted) blksize of 1024 instead of falling
+    // back to the RFC 2348 default of 512, it would mistake the first,
+    // full-size block for the last one and truncate the transfer.
+    let first_block = vec![b'a'; 512];
+    let second_block = vec![b'b'; 10];
+    let expected = [first_block.clone(), second_block.clone()].concat();
+
+    let server_thread = thread::spawn(move || {
+        let mut buf = [0u8; 1024];
+
+        let (_len, peer) = fake_server.recv_from(&mut buf).unwrap();
+
+        // Grant `tsize` but silently drop the requested `blksize`, as RFC
+        // 2348 permits a server to do for any option it doesn't support.
+        let mut oack = vec![0u8, 6u8];
+        oack.extend_from_slice(b"tsize");
+        oack.push(0);
+        oack.extend_from_slice(b"522");
+        oack.push(0);
+        fake_server.send_to(&oack, peer).unwrap();
+
+        let (_len, peer) = fake_server.recv_from(&mut buf).unwrap();
+        assert_eq!(u16::from_be_bytes([buf[0], buf[1]]), 4, "expected an Ack for block 0");
+
+        for (block, payload) in [(1u16, &first_block), (2u16, &second_block)] {
+            let mut data = vec![0u8, 3u8];
+            data.extend_from_slice(&block.to_be_bytes());
+            data.extend_from_slice(payload);
+            fake_server.send_to(&data, peer).unwrap();
+
+            let (_len, peer_again) = fake_server.recv_from(&mut buf).unwrap();
+            assert_eq!(peer_again, peer);
+            assert_eq!(u16::from_be_bytes([buf[0], buf[1]]), 4, "expected an Ack");
+            assert_eq!(u16::from_be_bytes([buf[2], buf[3]]), block);
+        }
+    });
+
+    let client = client::Builder::new()
+        .unwrap()
+        .connect_to(fake_server_addr)
+        .unwrap()
+        .blksize(1024)
+        .tsize(true)
+        .build();
+
+    let actual = client.get("whatever.txt", Mode::Octet, Vec::new()).unwrap();
+    assert_eq!(actual, expected);
+
+    server_thread.join().unwrap();
+}
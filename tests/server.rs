@@ -0,0 +1,122 @@
+use std::fs;
+use std::net::UdpSocket;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use tftp::client;
+use tftp::packet::Mode;
+use tftp::Server;
+
+fn scratch_dir(name: &str) -> std::path::PathBuf {
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    let mut dir = std::env::temp_dir();
+    dir.push(format!("tftp-server-tests-{name}-{}-{nanos}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn test_get_with_blksize_negotiation() {
+    let root = scratch_dir("blksize");
+    let served = root.join("served");
+    fs::create_dir_all(&served).unwrap();
+    let contents = b"alice was beginning to get very tired".repeat(10);
+    fs::write(served.join("big.txt"), &contents).unwrap();
+
+    let server_addr = "127.0.0.1:16956";
+    let server = Server::new(server_addr, &served).unwrap();
+    let server_thread = thread::spawn(move || {
+        let handler = server.serve().unwrap();
+        handler.handle().unwrap();
+    });
+
+    let client = client::Builder::new()
+        .unwrap()
+        .connect_to(server_addr)
+        .unwrap()
+        .blksize(32)
+        .build();
+
+    let actual = client.get("big.txt", Mode::Octet, Vec::new()).unwrap();
+    assert_eq!(actual, contents);
+
+    server_thread.join().unwrap();
+}
+
+#[test]
+fn test_rrq_traversal_is_rejected_with_access_violation() {
+    let root = scratch_dir("traversal");
+    let served = root.join("served");
+    fs::create_dir_all(&served).unwrap();
+    fs::write(root.join("secret.txt"), b"outside the serve root").unwrap();
+
+    let server_addr = "127.0.0.1:16957";
+    let server = Server::new(server_addr, &served).unwrap();
+    let server_thread = thread::spawn(move || {
+        let handler = server.serve().unwrap();
+        let _ = handler.handle();
+    });
+
+    let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+    socket.set_read_timeout(Some(Duration::from_secs(2))).unwrap();
+
+    let mut rrq = vec![0u8, 1u8];
+    rrq.extend_from_slice(b"../secret.txt");
+    rrq.push(0);
+    rrq.extend_from_slice(b"octet");
+    rrq.push(0);
+    socket.send_to(&rrq, server_addr).unwrap();
+
+    let mut buf = [0u8; 512];
+    let (_len, _) = socket.recv_from(&mut buf).unwrap();
+
+    assert_eq!(u16::from_be_bytes([buf[0], buf[1]]), 5, "expected an Error reply");
+    assert_eq!(
+        u16::from_be_bytes([buf[2], buf[3]]),
+        2,
+        "expected ErrorCode::AccessViolation (2)"
+    );
+
+    server_thread.join().unwrap();
+}
+
+#[test]
+fn test_rrq_with_invalid_timeout_option_is_refused() {
+    let root = scratch_dir("bad-timeout");
+    let served = root.join("served");
+    fs::create_dir_all(&served).unwrap();
+    fs::write(served.join("file.txt"), b"hi").unwrap();
+
+    let server_addr = "127.0.0.1:16958";
+    let server = Server::new(server_addr, &served).unwrap();
+    let server_thread = thread::spawn(move || {
+        let handler = server.serve().unwrap();
+        let _ = handler.handle();
+    });
+
+    let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+    socket.set_read_timeout(Some(Duration::from_secs(2))).unwrap();
+
+    let mut rrq = vec![0u8, 1u8];
+    rrq.extend_from_slice(b"file.txt");
+    rrq.push(0);
+    rrq.extend_from_slice(b"octet");
+    rrq.push(0);
+    rrq.extend_from_slice(b"timeout");
+    rrq.push(0);
+    rrq.extend_from_slice(b"999");
+    rrq.push(0);
+    socket.send_to(&rrq, server_addr).unwrap();
+
+    let mut buf = [0u8; 512];
+    let (_len, _) = socket.recv_from(&mut buf).unwrap();
+
+    assert_eq!(u16::from_be_bytes([buf[0], buf[1]]), 5, "expected an Error reply");
+    assert_eq!(
+        u16::from_be_bytes([buf[2], buf[3]]),
+        8,
+        "expected ErrorCode::OptionNegotiationFailed (8)"
+    );
+
+    server_thread.join().unwrap();
+}
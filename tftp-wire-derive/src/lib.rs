@@ -0,0 +1,204 @@
+//! Derive macros that generate `FromBytes`/`IntoBytes` implementations for
+//! TFTP packet structs from field-level `#[wire(..)]` attributes, modeled on
+//! the p9 crate's `wire_format_derive`.
+//!
+//! Supported field kinds:
+//!
+//! - `#[wire(u16)]` — a big-endian `u16` (block number), or any type `T`
+//!   with `T: TryFrom<u16>` and `u16: From<T>` (an enum-backed field like
+//!   `ErrorCode`).
+//! - `#[wire(cstr)]` — a NUL-terminated string (filename), or any type `T`
+//!   with `T: TryFrom<String>` and `String: From<T>` (a string-backed enum
+//!   like `Mode`).
+//! - `#[wire(bytes)]` — the remaining raw bytes (data payload). Must be the
+//!   last field.
+//! - `#[wire(options)]` — the remaining RFC 2347 `(option, value)` pairs
+//!   (`crate::packet::options::Options`). Must be the last field.
+//!
+//! Fields are read and written in declaration order, so the struct
+//! definition alone fixes the wire layout:
+//!
+//! ```ignore
+//! #[derive(FromBytes, IntoBytes)]
+//! struct Data {
+//!     #[wire(u16)]
+//!     block: u16,
+//!     #[wire(bytes)]
+//!     payload: Vec<u8>,
+//! }
+//! ```
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Field, Fields, FieldsNamed};
+
+enum WireKind {
+    U16,
+    CStr,
+    Bytes,
+    Options,
+}
+
+fn wire_kind(field: &Field) -> WireKind {
+    for attr in &field.attrs {
+        if !attr.path().is_ident("wire") {
+            continue;
+        }
+
+        let mut kind = None;
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("u16") {
+                kind = Some(WireKind::U16);
+            } else if meta.path.is_ident("cstr") {
+                kind = Some(WireKind::CStr);
+            } else if meta.path.is_ident("bytes") {
+                kind = Some(WireKind::Bytes);
+            } else if meta.path.is_ident("options") {
+                kind = Some(WireKind::Options);
+            } else {
+                return Err(meta.error("unsupported #[wire(..)] kind"));
+            }
+            Ok(())
+        })
+        .expect("invalid #[wire(..)] attribute");
+
+        if let Some(kind) = kind {
+            return kind;
+        }
+    }
+
+    panic!("every field of a FromBytes/IntoBytes struct must carry a #[wire(..)] attribute");
+}
+
+fn named_fields(data: &Data) -> &FieldsNamed {
+    match data {
+        Data::Struct(s) => match &s.fields {
+            Fields::Named(named) => named,
+            _ => panic!("FromBytes/IntoBytes can only be derived for structs with named fields"),
+        },
+        _ => panic!("FromBytes/IntoBytes can only be derived for structs"),
+    }
+}
+
+/// Derives `crate::bytes::FromBytes` by reading each `#[wire(..)]` field off
+/// a cursor in declaration order, surfacing `ErrorKind::InvalidInput` on
+/// truncation, a missing NUL terminator, or a value that doesn't convert
+/// into the field's type.
+#[proc_macro_derive(FromBytes, attributes(wire))]
+pub fn derive_from_bytes(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = input.ident;
+    let fields = named_fields(&input.data);
+
+    let mut decode_stmts = Vec::new();
+    let mut field_names = Vec::new();
+
+    for field in &fields.named {
+        let ident = field.ident.clone().unwrap();
+        let ty = &field.ty;
+        field_names.push(ident.clone());
+
+        let stmt = match wire_kind(field) {
+            WireKind::U16 => quote! {
+                if bytes.len() < cursor + 2 {
+                    return Err(::std::io::ErrorKind::InvalidInput.into());
+                }
+                let raw = u16::from_be_bytes([bytes[cursor], bytes[cursor + 1]]);
+                let #ident = <#ty as ::std::convert::TryFrom<u16>>::try_from(raw)
+                    .map_err(|_| -> ::std::io::Error { ::std::io::ErrorKind::InvalidInput.into() })?;
+                cursor += 2;
+            },
+            WireKind::CStr => quote! {
+                let nul = bytes[cursor..]
+                    .iter()
+                    .position(|b| *b == 0)
+                    .ok_or_else(|| -> ::std::io::Error { ::std::io::ErrorKind::InvalidInput.into() })?;
+                let raw = ::std::string::String::from_utf8(bytes[cursor..cursor + nul].to_vec())
+                    .map_err(|_| -> ::std::io::Error { ::std::io::ErrorKind::InvalidInput.into() })?;
+                let #ident = <#ty as ::std::convert::TryFrom<::std::string::String>>::try_from(raw)
+                    .map_err(|_| -> ::std::io::Error { ::std::io::ErrorKind::InvalidInput.into() })?;
+                cursor += nul + 1;
+            },
+            WireKind::Bytes => quote! {
+                let #ident = bytes[cursor..].to_vec();
+                cursor = bytes.len();
+            },
+            WireKind::Options => quote! {
+                let #ident = crate::packet::options::parse(&bytes[cursor..])?;
+                cursor = bytes.len();
+            },
+        };
+
+        decode_stmts.push(stmt);
+    }
+
+    let expanded = quote! {
+        impl crate::bytes::FromBytes for #name {
+            type Error = ::std::io::Error;
+
+            fn from_bytes<T: AsRef<[u8]>>(bytes: T) -> ::std::io::Result<Self> {
+                let bytes = bytes.as_ref();
+                let mut cursor = 0usize;
+
+                #(#decode_stmts)*
+
+                Ok(#name {
+                    #(#field_names),*
+                })
+            }
+        }
+    };
+
+    expanded.into()
+}
+
+/// Derives `crate::bytes::IntoBytes` by emitting each `#[wire(..)]` field in
+/// declaration order.
+#[proc_macro_derive(IntoBytes, attributes(wire))]
+pub fn derive_into_bytes(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = input.ident;
+    let fields = named_fields(&input.data);
+
+    let encode_stmts: Vec<_> = fields
+        .named
+        .iter()
+        .map(|field| {
+            let ident = field.ident.clone().unwrap();
+            let ty = &field.ty;
+
+            match wire_kind(field) {
+                WireKind::U16 => quote! {
+                    out.extend_from_slice(&<u16 as ::std::convert::From<#ty>>::from(self.#ident).to_be_bytes());
+                },
+                WireKind::CStr => quote! {
+                    out.extend_from_slice(<::std::string::String as ::std::convert::From<#ty>>::from(self.#ident).as_bytes());
+                    out.push(0);
+                },
+                WireKind::Bytes => quote! {
+                    out.extend_from_slice(&self.#ident);
+                },
+                WireKind::Options => quote! {
+                    out.extend_from_slice(&crate::packet::options::encode(&self.#ident));
+                },
+            }
+        })
+        .collect();
+
+    let expanded = quote! {
+        impl crate::bytes::IntoBytes for #name {
+            fn into_bytes(self) -> ::std::vec::Vec<u8> {
+                let mut out = ::std::vec::Vec::new();
+
+                #(#encode_stmts)*
+
+                out
+            }
+        }
+    };
+
+    expanded.into()
+}
+